@@ -1,12 +1,14 @@
 use image::{GenericImage, ImageBuffer};
 use definitions::Image;
+use rect::Rect;
 use std::f32;
 use std::i32;
 use drawing::draw_if_in_bounds;
 use drawing::line::draw_line_segment_mut;
 
 /// Draw as much of an ellipse as lies inside the image bounds.
-/// Uses Midpoint Ellipse Drawing Algorithm. (Modified from Bresenham's algorithm) (http://tutsheap.com/c/mid-point-ellipse-drawing-algorithm/)
+/// Uses an exact integer Bresenham-style quarter-ellipse walk, mirrored into
+/// the remaining three quadrants.
 ///
 /// The ellipse is axis-aligned and satisfies the following equation:
 ///
@@ -29,7 +31,8 @@ where
 }
 
 /// Draw as much of an ellipse as lies inside the image bounds.
-/// Uses Midpoint Ellipse Drawing Algorithm. (Modified from Bresenham's algorithm) (http://tutsheap.com/c/mid-point-ellipse-drawing-algorithm/)
+/// Uses an exact integer Bresenham-style quarter-ellipse walk, mirrored into
+/// the remaining three quadrants.
 ///
 /// The ellipse is axis-aligned and satisfies the following equation:
 ///
@@ -61,7 +64,8 @@ pub fn draw_hollow_ellipse_mut<I>(
 }
 
 /// Draw as much of an ellipse, including its contents, as lies inside the image bounds.
-/// Uses Midpoint Ellipse Drawing Algorithm. (Modified from Bresenham's algorithm) (http://tutsheap.com/c/mid-point-ellipse-drawing-algorithm/)
+/// Uses an exact integer Bresenham-style quarter-ellipse walk, mirrored into
+/// the remaining three quadrants.
 ///
 /// The ellipse is axis-aligned and satisfies the following equation:
 ///
@@ -84,7 +88,8 @@ where
 }
 
 /// Draw as much of an ellipse, including its contents, as lies inside the image bounds.
-/// Uses Midpoint Ellipse Drawing Algorithm. (Modified from Bresenham's algorithm) (http://tutsheap.com/c/mid-point-ellipse-drawing-algorithm/)
+/// Uses an exact integer Bresenham-style quarter-ellipse walk, mirrored into
+/// the remaining three quadrants.
 ///
 /// The ellipse is axis-aligned and satisfies the following equation:
 ///
@@ -123,55 +128,267 @@ pub fn draw_filled_ellipse_mut<I>(
     draw_ellipse(draw_line_pairs, center, width_radius, height_radius);
 }
 
-// Implements the Midpoint Ellipse Drawing Algorithm. (Modified from Bresenham's algorithm) (http://tutsheap.com/c/mid-point-ellipse-drawing-algorithm/)
-// Takes a function that determines how to render the points on the ellipse.
+// Renders an axis-aligned ellipse by walking one quarter of its boundary
+// and passing each point to `render_func`, which is responsible for
+// mirroring it as appropriate (e.g. into the remaining three quadrants for
+// a full ellipse, or offsetting it to render just one corner of a rounded
+// rectangle).
 fn draw_ellipse<F>(mut render_func: F, center: (i32, i32), width_radius: i32, height_radius: i32)
 where
     F: FnMut(i32, i32, i32, i32),
 {
     let (x0, y0) = center;
-    let w2 = width_radius * width_radius;
-    let h2 = height_radius * height_radius;
-    let mut x = 0;
-    let mut y = height_radius;
-    let mut px = 0;
-    let mut py = 2 * w2 * y;
-
-    render_func(x0, y0, x, y);
-
-    // Top and bottom regions.
-    let mut p = (h2 - (w2 * height_radius)) as f32 + (0.25 * w2 as f32);
-    while px < py {
-        x += 1;
-        px += 2 * h2;
-        if p < 0.0 {
-            p += (h2 + px) as f32;
-        } else {
-            y -= 1;
-            py += -2 * w2;
-            p += (h2 + px - py) as f32;
+    walk_ellipse_quarter(width_radius, height_radius, |x, y| {
+        render_func(x0, y0, x, y);
+    });
+}
+
+// Walks the top-right quarter (x, y both >= 0) of an axis-aligned ellipse
+// with semi-axes `width_radius` (a) and `height_radius` (b), calling
+// `emit(x, y)` for each pixel on the boundary.
+//
+// This is an exact integer Bresenham-style walk, matching Pillow's
+// corrected ellipse renderer: it starts at the true axis intercept `(a, 0)`
+// and ends at the other true axis intercept `(0, b)`. At each step, among
+// the (up to three) candidate next pixels moving towards the end, the one
+// minimizing the curve deviation `|a^2*y^2 + b^2*x^2 - a^2*b^2|` is chosen.
+// Using an integer decision variable (rather than the previous algorithm's
+// float midpoint `p`) keeps the walk exactly symmetric, which avoids the
+// lopsided, gappy outlines the old algorithm produced at small radii.
+//
+// Starting and ending at the true intercepts (rather than at a parity
+// offset from them) is essential: the walk always advances `y` towards `b`
+// and `x` towards `0`, so it only ever visits `y == 0` if it starts there,
+// and only ever visits `x == 0` if it ends there. Offsetting either end by
+// a parity term skips that axis intercept entirely, leaving a gap straight
+// through the row (or column) it would have occupied.
+fn walk_ellipse_quarter<F: FnMut(i32, i32)>(width_radius: i32, height_radius: i32, mut emit: F) {
+    let a = width_radius;
+    let b = height_radius;
+    let a2 = i64::from(a) * i64::from(a);
+    let b2 = i64::from(b) * i64::from(b);
+    let a2b2 = a2 * b2;
+
+    let delta = |x: i32, y: i32| (a2 * i64::from(y) * i64::from(y) + b2 * i64::from(x) * i64::from(x) - a2b2).abs();
+
+    let mut x = a;
+    let mut y = 0;
+    let ex = 0;
+    let ey = b;
+
+    loop {
+        emit(x, y);
+        if x == ex && y == ey {
+            break;
         }
 
-        render_func(x0, y0, x, y);
-    }
+        let can_move_x = x > ex;
+        let can_move_y = y < ey;
 
-    // Left and right regions.
-    p = (h2 as f32) * (x as f32 + 0.5).powi(2) + (w2 * (y - 1).pow(2)) as f32 - (w2 * h2) as f32;
-    while y > 0 {
-        y -= 1;
-        py += -2 * w2;
-        if p > 0.0 {
-            p += (w2 - py) as f32;
+        let (nx, ny) = if can_move_x && can_move_y {
+            *[(x - 1, y), (x, y + 1), (x - 1, y + 1)]
+                .iter()
+                .min_by_key(|&&(cx, cy)| delta(cx, cy))
+                .unwrap()
+        } else if can_move_x {
+            (x - 1, y)
         } else {
-            x += 1;
-            px += 2 * h2;
-            p += (w2 - py + px) as f32;
+            (x, y + 1)
+        };
+
+        x = nx;
+        y = ny;
+    }
+}
+
+/// Draw as much of a rotated ellipse as lies inside the image bounds.
+///
+/// The ellipse has semi-axes `width_radius` and `height_radius` before
+/// rotation, and is rotated counter-clockwise about `center` by `angle`
+/// radians.
+pub fn draw_hollow_rotated_ellipse<I>(
+    image: &I,
+    center: (i32, i32),
+    width_radius: i32,
+    height_radius: i32,
+    angle: f32,
+    color: I::Pixel,
+) -> Image<I::Pixel>
+where
+    I: GenericImage,
+    I::Pixel: 'static,
+{
+    let mut out = ImageBuffer::new(image.width(), image.height());
+    out.copy_from(image, 0, 0);
+    draw_hollow_rotated_ellipse_mut(&mut out, center, width_radius, height_radius, angle, color);
+    out
+}
+
+/// Draw as much of a rotated ellipse as lies inside the image bounds.
+///
+/// The ellipse has semi-axes `width_radius` and `height_radius` before
+/// rotation, and is rotated counter-clockwise about `center` by `angle`
+/// radians.
+pub fn draw_hollow_rotated_ellipse_mut<I>(
+    image: &mut I,
+    center: (i32, i32),
+    width_radius: i32,
+    height_radius: i32,
+    angle: f32,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    I::Pixel: 'static,
+{
+    let points = rotated_ellipse_boundary_points(center, width_radius, height_radius, angle);
+
+    for i in 0..points.len() {
+        let start = points[i];
+        let end = points[(i + 1) % points.len()];
+        draw_line_segment_mut(image, start, end, color);
+    }
+}
+
+/// Draw as much of a rotated ellipse, including its contents, as lies inside the image bounds.
+///
+/// The ellipse has semi-axes `width_radius` and `height_radius` before
+/// rotation, and is rotated counter-clockwise about `center` by `angle`
+/// radians.
+pub fn draw_filled_rotated_ellipse<I>(
+    image: &I,
+    center: (i32, i32),
+    width_radius: i32,
+    height_radius: i32,
+    angle: f32,
+    color: I::Pixel,
+) -> Image<I::Pixel>
+where
+    I: GenericImage,
+    I::Pixel: 'static,
+{
+    let mut out = ImageBuffer::new(image.width(), image.height());
+    out.copy_from(image, 0, 0);
+    draw_filled_rotated_ellipse_mut(&mut out, center, width_radius, height_radius, angle, color);
+    out
+}
+
+/// Draw as much of a rotated ellipse, including its contents, as lies inside the image bounds.
+///
+/// The ellipse has semi-axes `width_radius` and `height_radius` before
+/// rotation, and is rotated counter-clockwise about `center` by `angle`
+/// radians.
+///
+/// For each scanline in the rotated ellipse's bounding box, the entry and
+/// exit points of the rotated ellipse boundary are found directly by
+/// solving the rotated ellipse inequality for `x`, so that the interior is
+/// drawn as a single horizontal line per row with no double-covered pixels.
+pub fn draw_filled_rotated_ellipse_mut<I>(
+    image: &mut I,
+    center: (i32, i32),
+    width_radius: i32,
+    height_radius: i32,
+    angle: f32,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    I::Pixel: 'static,
+{
+    let (cx, cy) = center;
+    let a = width_radius as f32;
+    let b = height_radius as f32;
+    let cos_t = angle.cos();
+    let sin_t = angle.sin();
+
+    // Degenerate case: a circle is rotationally symmetric, so there's
+    // nothing for the rotation to do.
+    if width_radius == height_radius {
+        draw_filled_circle_mut(image, center, width_radius, color);
+        return;
+    }
+
+    // Degenerate case: if exactly one radius is zero, the ellipse collapses
+    // to a line segment along the other semi-axis, rotated by `angle`.
+    // Solving the quadratic below would divide by `a * a` or `b * b`, which
+    // is zero here.
+    if width_radius == 0 || height_radius == 0 {
+        let (ex, ey) = if width_radius == 0 { (0.0, b) } else { (a, 0.0) };
+        let dx = ex * cos_t - ey * sin_t;
+        let dy = ex * sin_t + ey * cos_t;
+        draw_line_segment_mut(
+            image,
+            (cx as f32 - dx, cy as f32 - dy),
+            (cx as f32 + dx, cy as f32 + dy),
+            color,
+        );
+        return;
+    }
+
+    let half_height = ((a * sin_t).powi(2) + (b * cos_t).powi(2)).sqrt();
+    let y_min = cy - half_height.ceil() as i32;
+    let y_max = cy + half_height.ceil() as i32;
+
+    // Coefficients of the quadratic `coeff_a * dx^2 + coeff_b * dx + coeff_c <= 0`
+    // obtained by substituting the un-rotated ellipse equation with
+    // `dx = x - cx`, `dy = y - cy`.
+    let coeff_a = (cos_t / a).powi(2) + (sin_t / b).powi(2);
+    let cross = 2.0 * cos_t * sin_t * (1.0 / (a * a) - 1.0 / (b * b));
+
+    for y in y_min..=y_max {
+        let dy = (y - cy) as f32;
+        let coeff_b = cross * dy;
+        let coeff_c = dy.powi(2) * ((sin_t / a).powi(2) + (cos_t / b).powi(2)) - 1.0;
+
+        let discriminant = coeff_b.powi(2) - 4.0 * coeff_a * coeff_c;
+        if discriminant < 0.0 {
+            continue;
         }
 
-        render_func(x0, y0, x, y);
+        let sqrt_d = discriminant.sqrt();
+        let dx_lo = (-coeff_b - sqrt_d) / (2.0 * coeff_a);
+        let dx_hi = (-coeff_b + sqrt_d) / (2.0 * coeff_a);
+
+        draw_line_segment_mut(
+            image,
+            (cx as f32 + dx_lo, y as f32),
+            (cx as f32 + dx_hi, y as f32),
+            color,
+        );
     }
 }
 
+/// Returns points on the boundary of a rotated ellipse, evenly spaced in the
+/// parameter `t`, suitable for connecting with line segments to approximate
+/// the outline.
+fn rotated_ellipse_boundary_points(
+    center: (i32, i32),
+    width_radius: i32,
+    height_radius: i32,
+    angle: f32,
+) -> Vec<(f32, f32)> {
+    let (cx, cy) = center;
+    let a = width_radius as f32;
+    let b = height_radius as f32;
+    let cos_t = angle.cos();
+    let sin_t = angle.sin();
+
+    // Choose enough steps that consecutive points are at most roughly a
+    // pixel apart, using the larger semi-axis as a stand-in for the
+    // ellipse's circumference.
+    let steps = ((a.max(b) * 2.0 * f32::consts::PI).ceil() as usize).max(16);
+
+    (0..steps)
+        .map(|i| {
+            let t = (i as f32 / steps as f32) * 2.0 * f32::consts::PI;
+            let x = a * t.cos();
+            let y = b * t.sin();
+            (
+                cx as f32 + x * cos_t - y * sin_t,
+                cy as f32 + x * sin_t + y * cos_t,
+            )
+        })
+        .collect()
+}
+
 /// Draw as much of a circle as lies inside the image bounds.
 pub fn draw_hollow_circle<I>(
     image: &I,
@@ -284,12 +501,1003 @@ where
     out
 }
 
+/// Draws as much of an anti-aliased ellipse, including its contents, as lies
+/// inside the image bounds.
+///
+/// Scanlines are classified into a fully-inside span, which is bulk-filled
+/// with `color` at full opacity, and a band of boundary pixels on either
+/// side of it whose width adapts to the local curvature of the ellipse (see
+/// [`ellipse_row_coverage_band`]). Boundary pixels have their coverage
+/// estimated from their distance to the ellipse curve and are blended into
+/// the image via `blend`, which is typically `(existing_pixel, color,
+/// coverage) -> blended_pixel`.
+pub fn draw_antialiased_filled_ellipse_mut<I, B>(
+    image: &mut I,
+    center: (i32, i32),
+    width_radius: i32,
+    height_radius: i32,
+    color: I::Pixel,
+    blend: B,
+) where
+    I: GenericImage,
+    I::Pixel: 'static,
+    B: Fn(I::Pixel, I::Pixel, f32) -> I::Pixel,
+{
+    let (cx, cy) = center;
+    let a = width_radius as f32;
+    let b = height_radius as f32;
+
+    for dy in 0..=(height_radius + 1) {
+        let dyf = dy as f32;
+        let (inner, outer) = ellipse_row_coverage_band(dyf, a, b);
+
+        // Pixels at or within `inner` have (within `COVERAGE_EPSILON`) full
+        // coverage, so fill them in bulk rather than paying the per-pixel
+        // blending cost for the whole interior.
+        if inner >= 0 {
+            draw_line_segment_mut(
+                image,
+                ((cx - inner) as f32, (cy + dy) as f32),
+                ((cx + inner) as f32, (cy + dy) as f32),
+                color,
+            );
+            if dy != 0 {
+                draw_line_segment_mut(
+                    image,
+                    ((cx - inner) as f32, (cy - dy) as f32),
+                    ((cx + inner) as f32, (cy - dy) as f32),
+                    color,
+                );
+            }
+        }
+
+        // Antialias the boundary pixels on either side of the bulk-filled span.
+        for dx in (inner + 1).max(0)..=outer {
+            let coverage = ellipse_coverage(dx as f32, dyf, a, b);
+            if coverage <= 0.0 {
+                continue;
+            }
+            blend_if_in_bounds(image, cx + dx, cy + dy, color, coverage, &blend);
+            if dx != 0 {
+                blend_if_in_bounds(image, cx - dx, cy + dy, color, coverage, &blend);
+            }
+            if dy != 0 {
+                blend_if_in_bounds(image, cx + dx, cy - dy, color, coverage, &blend);
+                if dx != 0 {
+                    blend_if_in_bounds(image, cx - dx, cy - dy, color, coverage, &blend);
+                }
+            }
+        }
+    }
+}
+
+/// Draws an anti-aliased circle outline as much as lies inside the image
+/// bounds.
+///
+/// Each outline pixel has its coverage estimated from its distance to the
+/// circle curve and is blended into the image via `blend`, which is
+/// typically `(existing_pixel, color, coverage) -> blended_pixel`.
+pub fn draw_antialiased_hollow_circle_mut<I, B>(
+    image: &mut I,
+    center: (i32, i32),
+    radius: i32,
+    color: I::Pixel,
+    blend: B,
+) where
+    I: GenericImage,
+    I::Pixel: 'static,
+    B: Fn(I::Pixel, I::Pixel, f32) -> I::Pixel,
+{
+    for_each_ellipse_boundary_pixel(center, radius, radius, |x, y, coverage| {
+        blend_if_in_bounds(image, x, y, color, coverage, &blend);
+    });
+}
+
+/// Returns the coverage, in `[0.0, 1.0]`, of the pixel at offset `(dx, dy)`
+/// from the center of an axis-aligned ellipse with semi-axes `a` and `b`.
+///
+/// This approximates the signed distance from the pixel center to the
+/// ellipse curve as `f(dx, dy) / |grad f(dx, dy)|`, where `f(x, y) = (x/a)^2
+/// + (y/b)^2 - 1` is negative inside the ellipse and zero on its boundary.
+/// A distance of `+-0.5` pixels maps to `0.0`/`1.0` coverage.
+fn ellipse_coverage(dx: f32, dy: f32, a: f32, b: f32) -> f32 {
+    let f = (dx / a).powi(2) + (dy / b).powi(2) - 1.0;
+    let grad_x = 2.0 * dx / (a * a);
+    let grad_y = 2.0 * dy / (b * b);
+    let grad_len = (grad_x * grad_x + grad_y * grad_y).sqrt();
+
+    if grad_len == 0.0 {
+        return if f <= 0.0 { 1.0 } else { 0.0 };
+    }
+
+    let distance = f / grad_len;
+    (0.5 - distance).max(0.0).min(1.0)
+}
+
+/// Coverage values within this distance of `0.0` or `1.0` are treated as
+/// "fully uncovered" or "fully covered" by [`ellipse_row_coverage_band`].
+const COVERAGE_EPSILON: f32 = 1e-3;
+
+/// Returns, for the scanline at vertical offset `dyf` from the center of an
+/// axis-aligned ellipse with semi-axes `a` and `b`, the horizontal offsets
+/// `(inner, outer)` bounding the band of pixels that need individual
+/// anti-aliasing treatment: pixels at `dx <= inner` have full coverage
+/// (within [`COVERAGE_EPSILON`]) and pixels at `dx > outer` have none.
+///
+/// `ellipse_coverage` decays with distance from the analytic edge at a rate
+/// that depends on how steep the curve is at that scanline, so a fixed pixel
+/// margin around the analytic edge is too narrow near the poles of an
+/// eccentric ellipse (clipping pixels that are still partially covered) and
+/// too wide along a circle's equator (paying for blending that was never
+/// needed). Instead, search outward from the analytic edge for where
+/// coverage actually crosses each threshold.
+fn ellipse_row_coverage_band(dyf: f32, a: f32, b: f32) -> (i32, i32) {
+    let inner = largest_satisfying(|dx| ellipse_coverage(dx as f32, dyf, a, b) >= 1.0 - COVERAGE_EPSILON);
+    let outer = largest_satisfying(|dx| ellipse_coverage(dx as f32, dyf, a, b) > COVERAGE_EPSILON).max(inner.max(0));
+    (inner, outer)
+}
+
+/// Returns the largest `dx >= 0` for which `pred(dx)` holds, or `-1` if
+/// `pred(0)` is already false. Assumes `pred` is true for some initial run of
+/// non-negative integers and then false from some point on (as `ellipse_coverage`
+/// is with respect to `dx`), and finds that crossing point in `O(log dx)` calls
+/// to `pred` via exponential search followed by binary search, rather than
+/// the `O(dx)` calls a linear walk would need for ellipses with very flat or
+/// very eccentric curvature.
+fn largest_satisfying<P: Fn(i32) -> bool>(pred: P) -> i32 {
+    if !pred(0) {
+        return -1;
+    }
+
+    let mut lo = 0;
+    let mut hi = 1;
+    while hi < i32::max_value() / 2 && pred(hi) {
+        lo = hi;
+        hi *= 2;
+    }
+    if pred(hi) {
+        return hi;
+    }
+
+    while lo + 1 < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Calls `f(x, y, coverage)` for each pixel in the band around the boundary
+/// of an axis-aligned ellipse returned by [`ellipse_row_coverage_band`], with
+/// `coverage` the value returned by `ellipse_coverage`. Exploits the
+/// ellipse's four-fold symmetry so that each coverage value is computed only
+/// once per quadrant.
+fn for_each_ellipse_boundary_pixel<F: FnMut(i32, i32, f32)>(
+    center: (i32, i32),
+    width_radius: i32,
+    height_radius: i32,
+    mut f: F,
+) {
+    let (cx, cy) = center;
+    let a = width_radius as f32;
+    let b = height_radius as f32;
+
+    for dy in 0..=(height_radius + 1) {
+        let dyf = dy as f32;
+        let (inner, outer) = ellipse_row_coverage_band(dyf, a, b);
+        let lo = (inner - 1).max(0);
+        let hi = outer;
+
+        for dx in lo..=hi {
+            let coverage = ellipse_coverage(dx as f32, dyf, a, b);
+            if coverage <= 0.0 {
+                continue;
+            }
+
+            f(cx + dx, cy + dy, coverage);
+            if dx != 0 {
+                f(cx - dx, cy + dy, coverage);
+            }
+            if dy != 0 {
+                f(cx + dx, cy - dy, coverage);
+                if dx != 0 {
+                    f(cx - dx, cy - dy, coverage);
+                }
+            }
+        }
+    }
+}
+
+/// Blends `color` into the pixel at `(x, y)` with the given `coverage` via
+/// `blend`, if `(x, y)` lies within the image bounds.
+fn blend_if_in_bounds<I, B>(
+    image: &mut I,
+    x: i32,
+    y: i32,
+    color: I::Pixel,
+    coverage: f32,
+    blend: &B,
+) where
+    I: GenericImage,
+    I::Pixel: 'static,
+    B: Fn(I::Pixel, I::Pixel, f32) -> I::Pixel,
+{
+    if x < 0 || y < 0 || x >= image.width() as i32 || y >= image.height() as i32 {
+        return;
+    }
+
+    let existing = image.get_pixel(x as u32, y as u32);
+    let blended = blend(existing, color, coverage);
+    image.put_pixel(x as u32, y as u32, blended);
+}
+
+/// The radii, as `(width_radius, height_radius)` pairs, of the four corners
+/// of a rounded rectangle.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RoundedRectRadii {
+    /// Radius of the top-left corner.
+    pub top_left: (i32, i32),
+    /// Radius of the top-right corner.
+    pub top_right: (i32, i32),
+    /// Radius of the bottom-left corner.
+    pub bottom_left: (i32, i32),
+    /// Radius of the bottom-right corner.
+    pub bottom_right: (i32, i32),
+}
+
+impl RoundedRectRadii {
+    /// Creates a `RoundedRectRadii` whose four corners all share the same
+    /// `(width_radius, height_radius)`.
+    pub fn all(width_radius: i32, height_radius: i32) -> Self {
+        let radius = (width_radius, height_radius);
+        RoundedRectRadii {
+            top_left: radius,
+            top_right: radius,
+            bottom_left: radius,
+            bottom_right: radius,
+        }
+    }
+}
+
+/// The per-corner radii of a `RoundedRectRadii`, clamped so that no corner's
+/// radii overlap its neighbours, together with the center of each corner's
+/// quarter-ellipse arc.
+struct ClampedCorners {
+    tl: (i32, i32),
+    tr: (i32, i32),
+    bl: (i32, i32),
+    br: (i32, i32),
+    tl_center: (i32, i32),
+    tr_center: (i32, i32),
+    bl_center: (i32, i32),
+    br_center: (i32, i32),
+}
+
+impl ClampedCorners {
+    fn new(rect: Rect, radii: RoundedRectRadii) -> Self {
+        let max_rx = rect.width() as i32 / 2;
+        let max_ry = rect.height() as i32 / 2;
+        let clamp = |(rx, ry): (i32, i32)| (rx.max(0).min(max_rx), ry.max(0).min(max_ry));
+
+        let tl = clamp(radii.top_left);
+        let tr = clamp(radii.top_right);
+        let bl = clamp(radii.bottom_left);
+        let br = clamp(radii.bottom_right);
+
+        ClampedCorners {
+            tl_center: (rect.left() + tl.0, rect.top() + tl.1),
+            tr_center: (rect.right() - tr.0, rect.top() + tr.1),
+            bl_center: (rect.left() + bl.0, rect.bottom() - bl.1),
+            br_center: (rect.right() - br.0, rect.bottom() - br.1),
+            tl,
+            tr,
+            bl,
+            br,
+        }
+    }
+}
+
+/// Draws as much of the outline of a rectangle with rounded corners as lies
+/// inside the image bounds.
+///
+/// Each corner's `(width_radius, height_radius)` in `radii` is clamped so
+/// that it does not exceed half of `rect`'s width/height, and is drawn as a
+/// quarter-ellipse arc. If all four radii are `(0, 0)`, this draws a plain
+/// rectangle outline.
+pub fn draw_hollow_rounded_rect<I>(
+    image: &I,
+    rect: Rect,
+    radii: RoundedRectRadii,
+    color: I::Pixel,
+) -> Image<I::Pixel>
+where
+    I: GenericImage,
+    I::Pixel: 'static,
+{
+    let mut out = ImageBuffer::new(image.width(), image.height());
+    out.copy_from(image, 0, 0);
+    draw_hollow_rounded_rect_mut(&mut out, rect, radii, color);
+    out
+}
+
+/// Draws as much of the outline of a rectangle with rounded corners as lies
+/// inside the image bounds.
+///
+/// Each corner's `(width_radius, height_radius)` in `radii` is clamped so
+/// that it does not exceed half of `rect`'s width/height, and is drawn as a
+/// quarter-ellipse arc. If all four radii are `(0, 0)`, this draws a plain
+/// rectangle outline.
+pub fn draw_hollow_rounded_rect_mut<I>(
+    image: &mut I,
+    rect: Rect,
+    radii: RoundedRectRadii,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    I::Pixel: 'static,
+{
+    let corners = ClampedCorners::new(rect, radii);
+
+    draw_line_segment_mut(
+        image,
+        (corners.tl_center.0 as f32, rect.top() as f32),
+        (corners.tr_center.0 as f32, rect.top() as f32),
+        color,
+    );
+    draw_line_segment_mut(
+        image,
+        (corners.bl_center.0 as f32, rect.bottom() as f32),
+        (corners.br_center.0 as f32, rect.bottom() as f32),
+        color,
+    );
+    draw_line_segment_mut(
+        image,
+        (rect.left() as f32, corners.tl_center.1 as f32),
+        (rect.left() as f32, corners.bl_center.1 as f32),
+        color,
+    );
+    draw_line_segment_mut(
+        image,
+        (rect.right() as f32, corners.tr_center.1 as f32),
+        (rect.right() as f32, corners.br_center.1 as f32),
+        color,
+    );
+
+    draw_ellipse(
+        |x0, y0, x, y| draw_if_in_bounds(image, x0 - x, y0 - y, color),
+        corners.tl_center,
+        corners.tl.0,
+        corners.tl.1,
+    );
+    draw_ellipse(
+        |x0, y0, x, y| draw_if_in_bounds(image, x0 + x, y0 - y, color),
+        corners.tr_center,
+        corners.tr.0,
+        corners.tr.1,
+    );
+    draw_ellipse(
+        |x0, y0, x, y| draw_if_in_bounds(image, x0 - x, y0 + y, color),
+        corners.bl_center,
+        corners.bl.0,
+        corners.bl.1,
+    );
+    draw_ellipse(
+        |x0, y0, x, y| draw_if_in_bounds(image, x0 + x, y0 + y, color),
+        corners.br_center,
+        corners.br.0,
+        corners.br.1,
+    );
+}
+
+/// Draws as much of a rectangle with rounded corners, including its
+/// contents, as lies inside the image bounds.
+///
+/// Each corner's `(width_radius, height_radius)` in `radii` is clamped so
+/// that it does not exceed half of `rect`'s width/height. If all four radii
+/// are `(0, 0)`, this draws a plain filled rectangle.
+pub fn draw_filled_rounded_rect<I>(
+    image: &I,
+    rect: Rect,
+    radii: RoundedRectRadii,
+    color: I::Pixel,
+) -> Image<I::Pixel>
+where
+    I: GenericImage,
+    I::Pixel: 'static,
+{
+    let mut out = ImageBuffer::new(image.width(), image.height());
+    out.copy_from(image, 0, 0);
+    draw_filled_rounded_rect_mut(&mut out, rect, radii, color);
+    out
+}
+
+/// Draws as much of a rectangle with rounded corners, including its
+/// contents, as lies inside the image bounds.
+///
+/// Each corner's `(width_radius, height_radius)` in `radii` is clamped so
+/// that it does not exceed half of `rect`'s width/height. If all four radii
+/// are `(0, 0)`, this draws a plain filled rectangle.
+pub fn draw_filled_rounded_rect_mut<I>(
+    image: &mut I,
+    rect: Rect,
+    radii: RoundedRectRadii,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    I::Pixel: 'static,
+{
+    let corners = ClampedCorners::new(rect, radii);
+
+    for y in rect.top()..=rect.bottom() {
+        let left_x = if y < corners.tl_center.1 {
+            corner_x_intercept(corners.tl_center, corners.tl, y, false)
+        } else if y > corners.bl_center.1 {
+            corner_x_intercept(corners.bl_center, corners.bl, y, false)
+        } else {
+            rect.left()
+        };
+
+        let right_x = if y < corners.tr_center.1 {
+            corner_x_intercept(corners.tr_center, corners.tr, y, true)
+        } else if y > corners.br_center.1 {
+            corner_x_intercept(corners.br_center, corners.br, y, true)
+        } else {
+            rect.right()
+        };
+
+        draw_line_segment_mut(
+            image,
+            (left_x as f32, y as f32),
+            (right_x as f32, y as f32),
+            color,
+        );
+    }
+}
+
+/// Returns the x-coordinate at which a horizontal scanline at `y` enters
+/// (`right_side = false`) or exits (`right_side = true`) the quarter-ellipse
+/// arc centered at `center` with the given `(width_radius, height_radius)`.
+fn corner_x_intercept(center: (i32, i32), radius: (i32, i32), y: i32, right_side: bool) -> i32 {
+    let (cx, cy) = center;
+    let (rx, ry) = radius;
+
+    if ry == 0 {
+        return cx;
+    }
+
+    let dy = (y - cy) as f32 / ry as f32;
+    let t = (1.0 - dy * dy).max(0.0);
+    let dx = rx as f32 * t.sqrt();
+
+    if right_side {
+        cx + dx.round() as i32
+    } else {
+        cx - dx.round() as i32
+    }
+}
+
+/// Draws as much of the elliptical arc between `start_angle` and
+/// `end_angle` (in radians, measured counter-clockwise from the positive
+/// x-axis) as lies inside the image bounds.
+///
+/// If `end_angle` is less than `start_angle`, the arc wraps past a full
+/// turn. If `start_angle == end_angle`, nothing is drawn; a sweep of
+/// exactly `2 * PI` draws the same outline as [`draw_hollow_ellipse_mut`].
+pub fn draw_hollow_ellipse_arc_mut<I>(
+    image: &mut I,
+    center: (i32, i32),
+    width_radius: i32,
+    height_radius: i32,
+    start_angle: f32,
+    end_angle: f32,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    I::Pixel: 'static,
+{
+    let sweep = match arc_sweep(start_angle, end_angle) {
+        Some(sweep) => sweep,
+        None => return,
+    };
+
+    if (sweep - 2.0 * f32::consts::PI).abs() < 1e-3 {
+        draw_hollow_ellipse_mut(image, center, width_radius, height_radius, color);
+        return;
+    }
+
+    let points = ellipse_arc_points(center, width_radius, height_radius, start_angle, sweep);
+    for window in points.windows(2) {
+        draw_line_segment_mut(image, window[0], window[1], color);
+    }
+}
+
+/// Draws as much of the circular arc between `start_angle` and `end_angle`
+/// (in radians, measured counter-clockwise from the positive x-axis) as lies
+/// inside the image bounds.
+///
+/// If `end_angle` is less than `start_angle`, the arc wraps past a full
+/// turn. If `start_angle == end_angle`, nothing is drawn; a sweep of exactly
+/// `2 * PI` draws the same outline as [`draw_hollow_circle_mut`].
+pub fn draw_hollow_circle_arc_mut<I>(
+    image: &mut I,
+    center: (i32, i32),
+    radius: i32,
+    start_angle: f32,
+    end_angle: f32,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    I::Pixel: 'static,
+{
+    draw_hollow_ellipse_arc_mut(image, center, radius, radius, start_angle, end_angle, color);
+}
+
+/// Draws a filled "pie slice": the sector enclosed by the elliptical arc
+/// between `start_angle` and `end_angle` and the two radii connecting its
+/// endpoints to `center`.
+///
+/// If `end_angle` is less than `start_angle`, the arc wraps past a full
+/// turn, and sweeps spanning more than half a turn are handled correctly.
+/// If `start_angle == end_angle`, nothing is drawn.
+pub fn draw_filled_ellipse_arc_mut<I>(
+    image: &mut I,
+    center: (i32, i32),
+    width_radius: i32,
+    height_radius: i32,
+    start_angle: f32,
+    end_angle: f32,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    I::Pixel: 'static,
+{
+    let sweep = match arc_sweep(start_angle, end_angle) {
+        Some(sweep) => sweep,
+        None => return,
+    };
+
+    if (sweep - 2.0 * f32::consts::PI).abs() < 1e-3 {
+        draw_filled_ellipse_mut(image, center, width_radius, height_radius, color);
+        return;
+    }
+
+    let points = ellipse_arc_points(center, width_radius, height_radius, start_angle, sweep);
+
+    let mut polygon = Vec::with_capacity(points.len() + 1);
+    polygon.push((center.0 as f32, center.1 as f32));
+    polygon.extend(points);
+
+    draw_line_segment_mut(image, polygon[0], polygon[1], color);
+    draw_line_segment_mut(image, polygon[0], *polygon.last().unwrap(), color);
+
+    fill_polygon(image, &polygon, color);
+}
+
+/// Draws a filled "pie slice": the sector enclosed by the circular arc
+/// between `start_angle` and `end_angle` and the two radii connecting its
+/// endpoints to `center`.
+///
+/// If `end_angle` is less than `start_angle`, the arc wraps past a full
+/// turn, and sweeps spanning more than half a turn are handled correctly.
+/// If `start_angle == end_angle`, nothing is drawn.
+pub fn draw_filled_circle_arc_mut<I>(
+    image: &mut I,
+    center: (i32, i32),
+    radius: i32,
+    start_angle: f32,
+    end_angle: f32,
+    color: I::Pixel,
+) where
+    I: GenericImage,
+    I::Pixel: 'static,
+{
+    draw_filled_ellipse_arc_mut(image, center, radius, radius, start_angle, end_angle, color);
+}
+
+/// Returns the sweep, in `(0.0, 2 * PI]` radians, from `start_angle` to
+/// `end_angle`, normalizing away any number of full turns so that arc
+/// lengths derived from it are never over- or under-counted regardless of
+/// how far `start_angle` and `end_angle` stray outside a single turn.
+/// Returns `None` if the two angles are exactly equal.
+fn arc_sweep(start_angle: f32, end_angle: f32) -> Option<f32> {
+    let sweep = end_angle - start_angle;
+    if sweep == 0.0 {
+        return None;
+    }
+    let full_turn = 2.0 * f32::consts::PI;
+    let normalized = sweep.rem_euclid(full_turn);
+    Some(if normalized == 0.0 { full_turn } else { normalized })
+}
+
+/// Returns points on an axis-aligned elliptical arc, evenly spaced in the
+/// parameter `t` over `t in [start_angle, start_angle + sweep]`, with the
+/// number of steps chosen from the arc length so consecutive points are
+/// roughly a pixel apart.
+fn ellipse_arc_points(
+    center: (i32, i32),
+    width_radius: i32,
+    height_radius: i32,
+    start_angle: f32,
+    sweep: f32,
+) -> Vec<(f32, f32)> {
+    let (cx, cy) = center;
+    let a = width_radius as f32;
+    let b = height_radius as f32;
+    let steps = (sweep * a.max(b)).ceil().max(2.0) as usize;
+
+    (0..=steps)
+        .map(|i| {
+            let t = start_angle + sweep * (i as f32 / steps as f32);
+            (cx as f32 + a * t.cos(), cy as f32 + b * t.sin())
+        })
+        .collect()
+}
+
+/// Fills the closed polygon defined by `points` (with an implicit edge from
+/// the last point back to the first) using the even-odd scanline fill rule.
+/// Used to render pie slices, whose straight radii plus curved arc can span
+/// more than half a turn and so cannot be filled with a single horizontal
+/// span per scanline.
+fn fill_polygon<I>(image: &mut I, points: &[(f32, f32)], color: I::Pixel)
+where
+    I: GenericImage,
+    I::Pixel: 'static,
+{
+    if points.len() < 3 {
+        return;
+    }
+
+    let min_y = points
+        .iter()
+        .map(|p| p.1)
+        .fold(f32::INFINITY, f32::min)
+        .floor() as i32;
+    let max_y = points
+        .iter()
+        .map(|p| p.1)
+        .fold(f32::NEG_INFINITY, f32::max)
+        .ceil() as i32;
+
+    for y in min_y..=max_y {
+        let yf = y as f32 + 0.5;
+        let mut xs = Vec::new();
+
+        for i in 0..points.len() {
+            let (x0, y0) = points[i];
+            let (x1, y1) = points[(i + 1) % points.len()];
+
+            if (y0 <= yf && y1 > yf) || (y1 <= yf && y0 > yf) {
+                let t = (yf - y0) / (y1 - y0);
+                xs.push(x0 + t * (x1 - x0));
+            }
+        }
+
+        xs.sort_by(|p, q| p.partial_cmp(q).unwrap());
+
+        let mut i = 0;
+        while i + 1 < xs.len() {
+            draw_line_segment_mut(image, (xs[i], yf), (xs[i + 1], yf), color);
+            i += 2;
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use image::{GrayImage, Luma};
     use test::{Bencher, black_box};
 
+    // Regression test for a walk_ellipse_quarter bug where the walk started
+    // and ended at parity-offset points instead of the true axis intercepts
+    // `(width_radius, 0)` and `(0, height_radius)`. That skipped `y == 0`
+    // whenever `height_radius` was odd (and `x == 0` whenever `width_radius`
+    // was odd), splitting the filled ellipse into two disjoint blobs with an
+    // empty row straight across its horizontal center.
+    #[test]
+    fn filled_ellipse_has_no_empty_rows_or_columns_for_odd_radii() {
+        let width_radius = 5;
+        let height_radius = 3;
+        let center = (10, 10);
+        let background = Luma([0u8]);
+        let color = Luma([255u8]);
+
+        let mut image = GrayImage::from_pixel(21, 21, background);
+        draw_filled_ellipse_mut(&mut image, center, width_radius, height_radius, color);
+
+        for y in (center.1 - height_radius)..=(center.1 + height_radius) {
+            let row_has_color = (0..image.width())
+                .any(|x| image.get_pixel(x, y as u32)[0] == 255);
+            assert!(row_has_color, "row {} is empty", y);
+        }
+
+        for x in (center.0 - width_radius)..=(center.0 + width_radius) {
+            let column_has_color = (0..image.height())
+                .any(|y| image.get_pixel(x as u32, y)[0] == 255);
+            assert!(column_has_color, "column {} is empty", x);
+        }
+    }
+
+    // Regression test for `ellipse_row_coverage_band` using a fixed `+-1`/
+    // `+-1.5` pixel margin around the analytic edge instead of one that
+    // adapts to local curvature. Near the poles of an eccentric ellipse (or
+    // even near the top/bottom of a large circle), `ellipse_coverage` decays
+    // slowly, so a fixed margin both dropped pixels with non-negligible
+    // coverage just outside it and hard-painted pixels with less-than-full
+    // coverage just inside it.
+    #[test]
+    fn ellipse_row_coverage_band_brackets_all_non_negligible_coverage() {
+        let pairs = [(20.0, 5.0), (5.0, 20.0), (50.0, 3.0), (3.0, 50.0), (75.0, 75.0)];
+
+        for &(a, b) in &pairs {
+            for dy in 0..=(b as i32 + 1) {
+                let dyf = dy as f32;
+                let (inner, outer) = ellipse_row_coverage_band(dyf, a, b);
+
+                for dx in 0..=inner {
+                    let coverage = ellipse_coverage(dx as f32, dyf, a, b);
+                    assert!(
+                        coverage >= 1.0 - COVERAGE_EPSILON,
+                        "a={}, b={}, dy={}, dx={} inside bulk span has coverage {}, expected ~1.0",
+                        a, b, dy, dx, coverage
+                    );
+                }
+
+                // No pixel beyond the processed band may still have
+                // non-negligible coverage that would silently be dropped.
+                for dx in (outer + 1)..=(outer + 10) {
+                    let coverage = ellipse_coverage(dx as f32, dyf, a, b);
+                    assert!(
+                        coverage <= COVERAGE_EPSILON,
+                        "a={}, b={}, dy={}, dx={} beyond band has coverage {}, expected ~0.0",
+                        a, b, dy, dx, coverage
+                    );
+                }
+            }
+        }
+    }
+
+    // Regression test for an `arc_sweep` bug where the sweep was only ever
+    // adjusted by a single `2 * PI`, so angle inputs more than a turn apart
+    // (e.g. a negative `start_angle` far below `end_angle`) came back
+    // unnormalized and could exceed the documented `(0.0, 2 * PI]` range,
+    // causing `ellipse_arc_points` to over-draw the same arc multiple times.
+    #[test]
+    fn arc_sweep_is_always_within_one_turn() {
+        let two_pi = 2.0 * f32::consts::PI;
+        let cases = [
+            (0.0, two_pi / 2.0),
+            (0.0, two_pi),
+            (-10.0, 0.1),
+            (1.0, 0.5),
+            (-1.0, -0.5),
+            (0.0, 100.0),
+            (5.0, 5.0),
+        ];
+
+        for &(start, end) in &cases {
+            match arc_sweep(start, end) {
+                Some(sweep) => assert!(
+                    sweep > 0.0 && sweep <= two_pi,
+                    "arc_sweep({}, {}) = {} is outside (0.0, 2 * PI]",
+                    start, end, sweep
+                ),
+                None => assert_eq!(start, end, "arc_sweep({}, {}) unexpectedly returned None", start, end),
+            }
+        }
+    }
+
+    // Regression test for a `draw_filled_rotated_ellipse_mut` bug where a
+    // `width_radius` or `height_radius` of `0` (but not both, so the circle
+    // fast-path didn't trigger) divided by `a * a`/`b * b` of `0.0` in the
+    // quadratic solve, producing `NaN` spans that silently collapsed to a
+    // solid vertical line through the center instead of the rotated
+    // degenerate line segment.
+    #[test]
+    fn filled_rotated_ellipse_with_zero_width_radius_draws_rotated_segment_not_vertical_line() {
+        let height_radius = 10;
+        let center = (20, 20);
+        let angle = f32::consts::FRAC_PI_4;
+        let background = Luma([0u8]);
+        let color = Luma([255u8]);
+
+        let mut image = GrayImage::from_pixel(41, 41, background);
+        draw_filled_rotated_ellipse_mut(&mut image, center, 0, height_radius, angle, color);
+
+        let mut min_x = None;
+        let mut max_x = None;
+        for y in 0..image.height() {
+            for x in 0..image.width() {
+                if image.get_pixel(x, y)[0] == 255 {
+                    min_x = Some(min_x.map_or(x, |m: u32| m.min(x)));
+                    max_x = Some(max_x.map_or(x, |m: u32| m.max(x)));
+                }
+            }
+        }
+
+        let min_x = min_x.expect("expected some pixels to be drawn");
+        let max_x = max_x.expect("expected some pixels to be drawn");
+        assert!(
+            max_x - min_x > 2,
+            "expected a rotated line segment spanning multiple columns, got columns [{}, {}] (a vertical stroke)",
+            min_x, max_x
+        );
+    }
+
+    // Behavioral test for `draw_filled_rotated_ellipse_mut`: at `angle =
+    // 0.0` it should closely approximate the same pixels as the
+    // axis-aligned `draw_filled_ellipse_mut`, even though the two use
+    // different rasterizers (a per-row quadratic solve vs. an integer
+    // quarter-ellipse walk) and so don't match pixel-for-pixel at the poles.
+    #[test]
+    fn filled_rotated_ellipse_at_zero_angle_approximates_axis_aligned_fill() {
+        let width_radius = 40;
+        let height_radius = 25;
+        let center = (50, 50);
+        let background = Luma([0u8]);
+        let color = Luma([255u8]);
+
+        let mut axis_aligned = GrayImage::from_pixel(100, 100, background);
+        draw_filled_ellipse_mut(&mut axis_aligned, center, width_radius, height_radius, color);
+
+        let mut rotated = GrayImage::from_pixel(100, 100, background);
+        draw_filled_rotated_ellipse_mut(&mut rotated, center, width_radius, height_radius, 0.0, color);
+
+        let mut union = 0;
+        let mut intersection = 0;
+        for y in 0..axis_aligned.height() {
+            for x in 0..axis_aligned.width() {
+                let a_set = axis_aligned.get_pixel(x, y)[0] == 255;
+                let r_set = rotated.get_pixel(x, y)[0] == 255;
+                if a_set || r_set {
+                    union += 1;
+                }
+                if a_set && r_set {
+                    intersection += 1;
+                }
+            }
+        }
+
+        let overlap = intersection as f32 / union as f32;
+        assert!(
+            overlap > 0.95,
+            "rotated (angle=0.0) and axis-aligned filled ellipses overlap only {:.4}, expected > 0.95",
+            overlap
+        );
+    }
+
+    // Behavioral test for `draw_hollow_rotated_ellipse_mut`: at `angle =
+    // 0.0` the outline should reach out to roughly `+-width_radius`/
+    // `+-height_radius` from `center`, same as the axis-aligned ellipse it
+    // approximates with a sampled polygon.
+    #[test]
+    fn hollow_rotated_ellipse_at_zero_angle_has_expected_bounding_box() {
+        let width_radius = 15;
+        let height_radius = 8;
+        let center = (20, 20);
+        let background = Luma([0u8]);
+        let color = Luma([255u8]);
+
+        let mut image = GrayImage::from_pixel(41, 41, background);
+        draw_hollow_rotated_ellipse_mut(&mut image, center, width_radius, height_radius, 0.0, color);
+
+        let mut min_x = None;
+        let mut max_x = None;
+        let mut min_y = None;
+        let mut max_y = None;
+        for y in 0..image.height() {
+            for x in 0..image.width() {
+                if image.get_pixel(x, y)[0] == 255 {
+                    min_x = Some(min_x.map_or(x, |m: u32| m.min(x)));
+                    max_x = Some(max_x.map_or(x, |m: u32| m.max(x)));
+                    min_y = Some(min_y.map_or(y, |m: u32| m.min(y)));
+                    max_y = Some(max_y.map_or(y, |m: u32| m.max(y)));
+                }
+            }
+        }
+
+        let (min_x, max_x) = (min_x.unwrap(), max_x.unwrap());
+        let (min_y, max_y) = (min_y.unwrap(), max_y.unwrap());
+        let (cx, cy) = (center.0 as u32, center.1 as u32);
+
+        assert!(
+            (cx - min_x) as i32 >= width_radius - 1 && (max_x - cx) as i32 >= width_radius - 1,
+            "expected boundary to reach roughly +/-{} in x, got columns [{}, {}]",
+            width_radius, min_x, max_x
+        );
+        assert!(
+            (cy - min_y) as i32 >= height_radius - 1 && (max_y - cy) as i32 >= height_radius - 1,
+            "expected boundary to reach roughly +/-{} in y, got rows [{}, {}]",
+            height_radius, min_y, max_y
+        );
+    }
+
+    // Behavioral test for `draw_filled_rounded_rect_mut`: the doc comment
+    // says all-zero radii draw a plain filled rectangle, but nothing
+    // actually asserted that until now.
+    #[test]
+    fn filled_rounded_rect_with_zero_radii_matches_plain_rect() {
+        let rect = Rect::at(5, 5).of_size(20, 12);
+        let radii = RoundedRectRadii::all(0, 0);
+        let background = Luma([0u8]);
+        let color = Luma([255u8]);
+
+        let mut rounded = GrayImage::from_pixel(40, 30, background);
+        draw_filled_rounded_rect_mut(&mut rounded, rect, radii, color);
+
+        let mut plain = GrayImage::from_pixel(40, 30, background);
+        for y in rect.top()..=rect.bottom() {
+            for x in rect.left()..=rect.right() {
+                plain.put_pixel(x as u32, y as u32, color);
+            }
+        }
+
+        for y in 0..rounded.height() {
+            for x in 0..rounded.width() {
+                assert_eq!(
+                    rounded.get_pixel(x, y),
+                    plain.get_pixel(x, y),
+                    "pixel ({}, {}) differs from a plain filled rect",
+                    x, y
+                );
+            }
+        }
+    }
+
+    // Behavioral test for `draw_hollow_rounded_rect_mut`: the doc comment
+    // says all-zero radii draw a plain rectangle outline, but nothing
+    // actually asserted that until now.
+    #[test]
+    fn hollow_rounded_rect_with_zero_radii_matches_plain_rect_outline() {
+        let rect = Rect::at(5, 5).of_size(20, 12);
+        let radii = RoundedRectRadii::all(0, 0);
+        let background = Luma([0u8]);
+        let color = Luma([255u8]);
+
+        let mut rounded = GrayImage::from_pixel(40, 30, background);
+        draw_hollow_rounded_rect_mut(&mut rounded, rect, radii, color);
+
+        let mut plain = GrayImage::from_pixel(40, 30, background);
+        draw_line_segment_mut(
+            &mut plain,
+            (rect.left() as f32, rect.top() as f32),
+            (rect.right() as f32, rect.top() as f32),
+            color,
+        );
+        draw_line_segment_mut(
+            &mut plain,
+            (rect.left() as f32, rect.bottom() as f32),
+            (rect.right() as f32, rect.bottom() as f32),
+            color,
+        );
+        draw_line_segment_mut(
+            &mut plain,
+            (rect.left() as f32, rect.top() as f32),
+            (rect.left() as f32, rect.bottom() as f32),
+            color,
+        );
+        draw_line_segment_mut(
+            &mut plain,
+            (rect.right() as f32, rect.top() as f32),
+            (rect.right() as f32, rect.bottom() as f32),
+            color,
+        );
+
+        for y in 0..rounded.height() {
+            for x in 0..rounded.width() {
+                assert_eq!(
+                    rounded.get_pixel(x, y),
+                    plain.get_pixel(x, y),
+                    "pixel ({}, {}) differs from a plain rect outline",
+                    x, y
+                );
+            }
+        }
+    }
+
     macro_rules! bench_hollow_ellipse {
         ($name:ident, $center:expr, $width_radius:expr, $height_radius:expr) => {
             #[bench]
@@ -329,4 +1537,104 @@ mod test {
     bench_filled_ellipse!(bench_bench_filled_ellipse_circle, (200, 200), 80, 80);
     bench_filled_ellipse!(bench_bench_filled_ellipse_vertical, (200, 200), 40, 100);
     bench_filled_ellipse!(bench_bench_filled_ellipse_horizontal, (200, 200), 100, 40);
+
+    macro_rules! bench_rotated_ellipse {
+        ($name:ident, $center:expr, $width_radius:expr, $height_radius:expr, $angle:expr) => {
+            #[bench]
+            fn $name(b: &mut test::Bencher) {
+                use super::draw_hollow_rotated_ellipse_mut;
+
+                let mut image = GrayImage::new(500, 500);
+                let color = Luma([50u8]);
+                b.iter(|| {
+                    draw_hollow_rotated_ellipse_mut(
+                        &mut image, $center, $width_radius, $height_radius, $angle, color,
+                    );
+                    test::black_box(&image);
+                    });
+            }
+        }
+    }
+
+    bench_rotated_ellipse!(bench_bench_hollow_rotated_ellipse, (200, 200), 40, 100, 0.6);
+
+    macro_rules! bench_filled_rotated_ellipse {
+        ($name:ident, $center:expr, $width_radius:expr, $height_radius:expr, $angle:expr) => {
+            #[bench]
+            fn $name(b: &mut test::Bencher) {
+                use super::draw_filled_rotated_ellipse_mut;
+
+                let mut image = GrayImage::new(500, 500);
+                let color = Luma([50u8]);
+                b.iter(|| {
+                    draw_filled_rotated_ellipse_mut(
+                        &mut image, $center, $width_radius, $height_radius, $angle, color,
+                    );
+                    test::black_box(&image);
+                    });
+            }
+        }
+    }
+
+    bench_filled_rotated_ellipse!(bench_bench_filled_rotated_ellipse, (200, 200), 40, 100, 0.6);
+
+    fn alpha_blend(background: Luma<u8>, foreground: Luma<u8>, alpha: f32) -> Luma<u8> {
+        let bg = background[0] as f32;
+        let fg = foreground[0] as f32;
+        Luma([(fg * alpha + bg * (1.0 - alpha)) as u8])
+    }
+
+    #[bench]
+    fn bench_antialiased_filled_ellipse(b: &mut test::Bencher) {
+        let mut image = GrayImage::new(500, 500);
+        let color = Luma([50u8]);
+        b.iter(|| {
+            draw_antialiased_filled_ellipse_mut(&mut image, (200, 200), 40, 100, color, alpha_blend);
+            test::black_box(&image);
+            });
+    }
+
+    #[bench]
+    fn bench_antialiased_hollow_circle(b: &mut test::Bencher) {
+        let mut image = GrayImage::new(500, 500);
+        let color = Luma([50u8]);
+        b.iter(|| {
+            draw_antialiased_hollow_circle_mut(&mut image, (200, 200), 80, color, alpha_blend);
+            test::black_box(&image);
+            });
+    }
+
+    #[bench]
+    fn bench_filled_rounded_rect(b: &mut test::Bencher) {
+        use rect::Rect;
+
+        let mut image = GrayImage::new(500, 500);
+        let color = Luma([50u8]);
+        let rect = Rect::at(100, 100).of_size(300, 300);
+        let radii = RoundedRectRadii::all(40, 40);
+        b.iter(|| {
+            draw_filled_rounded_rect_mut(&mut image, rect, radii, color);
+            test::black_box(&image);
+            });
+    }
+
+    #[bench]
+    fn bench_hollow_ellipse_arc(b: &mut test::Bencher) {
+        let mut image = GrayImage::new(500, 500);
+        let color = Luma([50u8]);
+        b.iter(|| {
+            draw_hollow_ellipse_arc_mut(&mut image, (200, 200), 80, 120, 0.0, 4.2, color);
+            test::black_box(&image);
+            });
+    }
+
+    #[bench]
+    fn bench_filled_ellipse_arc(b: &mut test::Bencher) {
+        let mut image = GrayImage::new(500, 500);
+        let color = Luma([50u8]);
+        b.iter(|| {
+            draw_filled_ellipse_arc_mut(&mut image, (200, 200), 80, 120, 0.0, 4.2, color);
+            test::black_box(&image);
+            });
+    }
 }